@@ -0,0 +1,173 @@
+//! Discovery and application of `.cargo/config.toml`.
+//!
+//! cargo itself walks the current directory's `ancestors()` looking for the
+//! first `.cargo/config.toml` (or legacy `.cargo/config`) and merges it with
+//! any config found higher up. We only care about a handful of `[build]`
+//! keys, so rather than replicating cargo's full merge semantics we just
+//! take the nearest config file, which covers the common case of a
+//! workspace-level `rustflags`/`target` override.
+// Based on https://github.com/rust-mobile/xbuild's `find_cargo_config_for_workspace`
+
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct CargoConfig {
+    #[serde(default)]
+    pub(crate) build: BuildConfig,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+pub(crate) struct BuildConfig {
+    pub(crate) rustflags: Option<StringOrVec>,
+    pub(crate) target: Option<String>,
+}
+
+/// `rustflags` may be a single space-separated string or an array of
+/// arguments; cargo accepts both forms.
+#[derive(Debug, Clone)]
+pub(crate) enum StringOrVec {
+    String(String),
+    Vec(Vec<String>),
+}
+
+impl StringOrVec {
+    /// Joins the flags the way `RUSTFLAGS` expects: space separated.
+    pub(crate) fn to_rustflags(&self) -> String {
+        match self {
+            StringOrVec::String(s) => s.clone(),
+            StringOrVec::Vec(v) => v.join(" "),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for StringOrVec {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            String(String),
+            Vec(Vec<String>),
+        }
+
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::String(s) => StringOrVec::String(s),
+            Repr::Vec(v) => StringOrVec::Vec(v),
+        })
+    }
+}
+
+/// Walks `dir`'s ancestors looking for the first `.cargo/config.toml` (or
+/// `.cargo/config`), returning its path if found.
+pub(crate) fn find_cargo_config_for_workspace(dir: &Path) -> Option<PathBuf> {
+    for ancestor in dir.ancestors() {
+        let toml = ancestor.join(".cargo").join("config.toml");
+        if toml.is_file() {
+            return Some(toml);
+        }
+        let legacy = ancestor.join(".cargo").join("config");
+        if legacy.is_file() {
+            return Some(legacy);
+        }
+    }
+    None
+}
+
+/// Finds and parses the nearest `.cargo/config.toml` to `dir`, if any.
+pub(crate) fn load_cargo_config(dir: &Path) -> anyhow::Result<Option<CargoConfig>> {
+    let Some(path) = find_cargo_config_for_workspace(dir) else {
+        return Ok(None);
+    };
+    let content = std::fs::read_to_string(&path)?;
+    let config: CargoConfig = toml::from_str(&content)?;
+    Ok(Some(config))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn finds_config_in_ancestor_dir() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join(".cargo")).unwrap();
+        std::fs::write(tmp.path().join(".cargo").join("config.toml"), "").unwrap();
+
+        let nested = tmp.path().join("a").join("b");
+        std::fs::create_dir_all(&nested).unwrap();
+
+        assert_eq!(
+            find_cargo_config_for_workspace(&nested),
+            Some(tmp.path().join(".cargo").join("config.toml"))
+        );
+    }
+
+    #[test]
+    fn finds_legacy_config_without_toml_extension() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join(".cargo")).unwrap();
+        std::fs::write(tmp.path().join(".cargo").join("config"), "").unwrap();
+
+        assert_eq!(
+            find_cargo_config_for_workspace(tmp.path()),
+            Some(tmp.path().join(".cargo").join("config"))
+        );
+    }
+
+    #[test]
+    fn no_config_found_returns_none() {
+        let tmp = tempfile::tempdir().unwrap();
+        assert_eq!(load_cargo_config(tmp.path()).unwrap().map(|_| ()), None);
+    }
+
+    #[test]
+    fn rustflags_as_string() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join(".cargo")).unwrap();
+        std::fs::write(
+            tmp.path().join(".cargo").join("config.toml"),
+            "[build]\nrustflags = \"-C target-cpu=native\"\n",
+        )
+        .unwrap();
+
+        let config = load_cargo_config(tmp.path()).unwrap().unwrap();
+        assert_eq!(
+            config.build.rustflags.unwrap().to_rustflags(),
+            "-C target-cpu=native"
+        );
+    }
+
+    #[test]
+    fn rustflags_as_array() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join(".cargo")).unwrap();
+        std::fs::write(
+            tmp.path().join(".cargo").join("config.toml"),
+            "[build]\nrustflags = [\"-C\", \"target-cpu=native\"]\n",
+        )
+        .unwrap();
+
+        let config = load_cargo_config(tmp.path()).unwrap().unwrap();
+        assert_eq!(
+            config.build.rustflags.unwrap().to_rustflags(),
+            "-C target-cpu=native"
+        );
+    }
+
+    #[test]
+    fn build_target_is_parsed() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join(".cargo")).unwrap();
+        std::fs::write(
+            tmp.path().join(".cargo").join("config.toml"),
+            "[build]\ntarget = \"wasm32-unknown-unknown\"\n",
+        )
+        .unwrap();
+
+        let config = load_cargo_config(tmp.path()).unwrap().unwrap();
+        assert_eq!(config.build.target.as_deref(), Some("wasm32-unknown-unknown"));
+    }
+}
@@ -0,0 +1,47 @@
+//! The typed error surfaced when a `cargo rustc --pretty=expanded` invocation
+//! fails, as classified from its structured `--message-format` output rather
+//! than by matching on stderr text.
+
+use cargo_metadata::diagnostic::Diagnostic;
+use std::fmt::{Display, Formatter};
+
+/// Why [`crate::Expander::expand`] (or a sibling method) failed to produce
+/// expanded output.
+#[derive(Debug)]
+pub enum ExpandError {
+    /// `cargo` refused to resolve the manifest because it is a virtual
+    /// manifest with no `[workspace]` section. Callers that see this for a
+    /// registry dependency should retry against a copy of the package, as
+    /// `Expander::expand` already does internally.
+    ///
+    /// This is detected from `cargo`'s own stderr text rather than the
+    /// `--message-format=json` stream: manifest resolution happens before
+    /// `rustc` is ever invoked, so this failure never becomes a structured
+    /// `CompilerMessage` and has no diagnostic code to classify on.
+    MissingWorkspace,
+    /// `rustc` reported one or more compiler errors while building the
+    /// requested target, so no expanded output was produced.
+    Compile(Vec<Diagnostic>),
+    /// `rustc` exited successfully but wrote no expanded output.
+    EmptyOutput,
+}
+
+impl Display for ExpandError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ExpandError::MissingWorkspace => {
+                f.write_str("virtual manifests must be configured with [workspace]")
+            }
+            ExpandError::Compile(diagnostics) => {
+                writeln!(f, "failed to expand: rustc reported compiler errors")?;
+                for diagnostic in diagnostics {
+                    write!(f, "{}", diagnostic.rendered.as_deref().unwrap_or(&diagnostic.message))?;
+                }
+                Ok(())
+            }
+            ExpandError::EmptyOutput => f.write_str("rustc produced no expanded output"),
+        }
+    }
+}
+
+impl std::error::Error for ExpandError {}
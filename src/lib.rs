@@ -13,29 +13,94 @@
 //!
 
 use anyhow::Context;
-use cargo_metadata::{CargoOpt, Metadata, MetadataCommand, Package};
+use cargo_metadata::{
+    diagnostic::DiagnosticLevel, CargoOpt, Message, Metadata, MetadataCommand, Package,
+};
 use quote::quote;
 
 use std::{
     env,
     ffi::OsString,
-    fmt::{Display, Formatter},
-    path::Path,
+    path::{Path, PathBuf},
     process::{Command, Stdio},
 };
 use syn_select::Selector;
 
-/// Helper Error to determine whether `cargo metadata` failed for registry crates
-#[derive(Debug)]
-struct MissingWorkspace;
+mod cargo_config;
+mod error;
+
+pub use error::ExpandError;
+
+/// The cargo target to expand.
+///
+/// Mirrors the target kinds cargo itself distinguishes between (see
+/// `cargo_metadata::Target::kind`), so a requested `Target` can be matched
+/// directly against the `targets` of a resolved `Package`.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum Target {
+    /// The crate's library target, expanded with `--lib`
+    #[default]
+    Lib,
+    /// A `[[bin]]` target, expanded with `--bin <name>`
+    Bin(String),
+    /// An `[[example]]` target, expanded with `--example <name>`
+    Example(String),
+    /// A `[[test]]` target, expanded with `--test <name>`
+    Test(String),
+    /// A `[[bench]]` target, expanded with `--bench <name>`
+    Bench(String),
+}
 
-impl Display for MissingWorkspace {
-    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
-        f.write_str("virtual manifests must be configured with [workspace]")
+/// The `cargo_metadata::Target::kind` values that all describe a crate's
+/// library target. `kind` reports the actual crate-type(s) (`proc-macro`,
+/// `cdylib`, ...), not the literal string `"lib"`, so a proc-macro or
+/// cdylib/staticlib-only crate must still match `Target::Lib`.
+const LIB_KINDS: &[&str] = &["lib", "rlib", "dylib", "cdylib", "staticlib", "proc-macro"];
+
+impl Target {
+    /// The cargo-metadata `kind` string this target corresponds to.
+    fn kind(&self) -> &'static str {
+        match self {
+            Target::Lib => "lib",
+            Target::Bin(_) => "bin",
+            Target::Example(_) => "example",
+            Target::Test(_) => "test",
+            Target::Bench(_) => "bench",
+        }
+    }
+
+    /// The target's name, if it has one distinct from the package name.
+    fn name(&self) -> Option<&str> {
+        match self {
+            Target::Lib => None,
+            Target::Bin(name)
+            | Target::Example(name)
+            | Target::Test(name)
+            | Target::Bench(name) => Some(name),
+        }
     }
-}
 
-impl std::error::Error for MissingWorkspace {}
+    /// Appends the cargo flag selecting this target to `cmd`.
+    fn apply(&self, cmd: &mut Command) {
+        match self {
+            Target::Lib => {
+                cmd.arg("--lib");
+            }
+            Target::Bin(name) => {
+                cmd.arg("--bin").arg(name);
+            }
+            Target::Example(name) => {
+                cmd.arg("--example").arg(name);
+            }
+            Target::Test(name) => {
+                cmd.arg("--test").arg(name);
+            }
+            Target::Bench(name) => {
+                cmd.arg("--bench").arg(name);
+            }
+        }
+    }
+}
 
 /// How to apply cargo expand
 // Based on https://github.com/dtolnay/cargo-expand
@@ -55,6 +120,15 @@ pub struct Expander {
     pub unstable_flags: Vec<String>,
     /// The manifest path of the targeted crate, default is the current
     pub manifest_path: Option<String>,
+    /// The target to expand, default is the crate's library target
+    pub target: Target,
+    /// Skip discovery of a `.cargo/config.toml` in the manifest's ancestors.
+    /// The spawned `cargo` still reads the config of its own working
+    /// directory and any inherited `RUSTFLAGS`
+    pub no_cargo_config: bool,
+    /// The target triple to cross-compile for, default is the host target.
+    /// Takes precedence over a `.cargo/config.toml` `build.target`.
+    pub target_triple: Option<String>,
 }
 
 impl Expander {
@@ -93,20 +167,113 @@ impl Expander {
         self
     }
 
+    /// Sets the target to expand, default is the crate's library target.
+    pub fn with_target(mut self, target: Target) -> Self {
+        self.target = target;
+        self
+    }
+
+    /// Disables discovery of a `.cargo/config.toml` in the manifest's
+    /// ancestors, so its `build.rustflags` and `build.target` are not applied.
+    /// The spawned `cargo` still reads the config of its own working directory
+    /// and any inherited `RUSTFLAGS`.
+    pub fn without_cargo_config(mut self) -> Self {
+        self.no_cargo_config = true;
+        self
+    }
+
+    /// Cross-compiles for `triple` instead of the host target, so the
+    /// expansion reflects that target's `cfg` set (e.g. `wasm32-unknown-unknown`
+    /// or `aarch64-apple-ios`).
+    pub fn with_target_triple(mut self, triple: impl Into<String>) -> Self {
+        self.target_triple = Some(triple.into());
+        self
+    }
+
     /// Returns the expanded lib of the given dependency.
-    // Based on https://github.com/rsolomo/cargo-check
+    ///
+    /// The library target is the default; a target set with
+    /// [`Expander::with_target`] is expanded instead.
+    // Based on https://github.com/rsolomo/cargo-expand
     pub fn expand(&self, package: impl AsRef<str>) -> anyhow::Result<String> {
+        self.expand_target(package, self.target.clone())
+    }
+
+    /// Returns the expanded output of `target` within the given dependency.
+    ///
+    /// The requested `target` is validated against the `targets` of the
+    /// resolved `Package` before cargo is invoked, so an unknown bin/example/
+    /// test/bench name produces a clear error instead of a confusing cargo
+    /// failure.
+    pub fn expand_target(
+        &self,
+        package: impl AsRef<str>,
+        target: Target,
+    ) -> anyhow::Result<String> {
         let package = package.as_ref();
         let pkg = self.find_package(package)?;
+        self.validate_target(&pkg, &target)?;
+        self.expand_resolved(&pkg, &target)
+    }
+
+    /// Expands every member of the workspace rooted at [`Expander::manifest_path`].
+    ///
+    /// Unlike [`Expander::expand`], a failure expanding one member does not
+    /// abort the others: each member's result is reported alongside its
+    /// `Package` so a caller can inspect which crates expanded cleanly.
+    pub fn expand_workspace(&self) -> anyhow::Result<Vec<(Package, anyhow::Result<String>)>> {
+        let metadata = self.get_metadata()?;
+        let members: Vec<Package> = metadata
+            .packages
+            .into_iter()
+            .filter(|pkg| metadata.workspace_members.contains(&pkg.id))
+            .collect();
+
+        Ok(members
+            .into_iter()
+            .map(|pkg| {
+                let result = self
+                    .validate_target(&pkg, &self.target)
+                    .and_then(|_| self.expand_resolved(&pkg, &self.target));
+                (pkg, result)
+            })
+            .collect())
+    }
+
+    /// Expands an explicit subset of packages, by name.
+    ///
+    /// Like [`Expander::expand_workspace`], a compile failure for one package
+    /// does not prevent the others in `packages` from being expanded. The
+    /// metadata is fetched once up front (rather than once per package), so
+    /// looking up `N` packages costs a single `cargo metadata` invocation.
+    pub fn expand_many(
+        &self,
+        packages: &[&str],
+    ) -> anyhow::Result<Vec<(Package, anyhow::Result<String>)>> {
+        let metadata = self.get_metadata()?;
+        packages
+            .iter()
+            .map(|name| {
+                let pkg = find_package_in(&metadata, name)?;
+                let result = self
+                    .validate_target(&pkg, &self.target)
+                    .and_then(|_| self.expand_resolved(&pkg, &self.target));
+                Ok((pkg, result))
+            })
+            .collect()
+    }
 
+    /// Runs the expansion for an already-resolved `Package`, retrying against
+    /// a temporary copy if cargo reports a missing `[workspace]`.
+    fn expand_resolved(&self, pkg: &Package, target: &Target) -> anyhow::Result<String> {
         let manifest_path = pkg.manifest_path.to_string();
 
         // try to run cargo if it fails due to virtual manifest
-        match self.run_cargo(manifest_path) {
+        match self.run_cargo(manifest_path, target) {
             res @ Ok(_) => res,
             Err(err) => {
-                match err.downcast::<MissingWorkspace>() {
-                    Ok(_) => {
+                match err.downcast::<ExpandError>() {
+                    Ok(ExpandError::MissingWorkspace) => {
                         // need to make a copy for registry packages due to virtual manifests
                         let tmp =
                             tempdir::TempDir::new(&format!("dep-expand-{}", pkg.name)).unwrap();
@@ -125,21 +292,67 @@ impl Expander {
                         )?;
 
                         // try run again but on the copy
-                        self.run_cargo(tmp_package_dir.join("Cargo.toml"))
+                        self.run_cargo(tmp_package_dir.join("Cargo.toml"), target)
                     }
+                    Ok(other) => Err(other.into()),
                     Err(err) => Err(err),
                 }
             }
         }
     }
 
-    fn run_cargo(&self, manifest_path: impl AsRef<Path>) -> anyhow::Result<String> {
+    /// Checks that `target` actually exists on `pkg`, returning a descriptive
+    /// error before we ever shell out to cargo.
+    fn validate_target(&self, pkg: &Package, target: &Target) -> anyhow::Result<()> {
+        if target == &Target::Lib {
+            if pkg
+                .targets
+                .iter()
+                .any(|t| t.kind.iter().any(|k| LIB_KINDS.contains(&k.as_str())))
+            {
+                return Ok(());
+            }
+            anyhow::bail!("No lib target found for package: `{}`", pkg.name);
+        }
+
+        let name = target.name().expect("non-lib target always has a name");
+        let kind = target.kind();
+        if pkg
+            .targets
+            .iter()
+            .any(|t| t.name == name && t.kind.iter().any(|k| k == kind))
+        {
+            return Ok(());
+        }
+
+        anyhow::bail!(
+            "No such target `{}` of kind `{}` found for package: `{}`",
+            name,
+            kind,
+            pkg.name
+        )
+    }
+
+    fn run_cargo(&self, manifest_path: impl AsRef<Path>, target: &Target) -> anyhow::Result<String> {
         let mut builder = tempfile::Builder::new();
         builder.prefix("dep-expand");
         let outdir = builder.tempdir().expect("failed to create tmp file");
         let outfile_path = outdir.path().join("expanded");
 
+        let manifest_dir = manifest_path
+            .as_ref()
+            .parent()
+            .context("Failed to find manifest dir")?;
+        let config = if self.no_cargo_config {
+            None
+        } else {
+            cargo_config::load_cargo_config(manifest_dir)?
+        };
+
         let mut cmd = Command::new(cargo_binary());
+        if let Some(rustflags) = config.as_ref().and_then(|c| c.build.rustflags.as_ref()) {
+            cmd.env("RUSTFLAGS", rustflags.to_rustflags());
+        }
         cmd.arg("rustc");
 
         if self.tests {
@@ -164,35 +377,64 @@ impl Expander {
             cmd.arg("--no-default-features");
         }
 
-        cmd.arg("--lib")
-            .arg("--manifest-path")
-            .arg(manifest_path.as_ref());
+        target.apply(&mut cmd);
+        cmd.arg("--manifest-path").arg(manifest_path.as_ref());
+
+        let target_triple = resolve_target_triple(self.target_triple.as_deref(), config.as_ref());
+        if let Some(target_triple) = target_triple {
+            cmd.arg("--target").arg(target_triple);
+        }
 
         for unstable_flag in &self.unstable_flags {
             cmd.arg("-Z");
             cmd.arg(unstable_flag);
         }
 
+        cmd.arg("--message-format=json-diagnostic-rendered-ansi");
+
         cmd.arg("--")
             .arg("-o")
             .arg(&outfile_path)
             .arg("-Zunstable-options")
             .arg("--pretty=expanded");
 
-        let output = cmd.stderr(Stdio::piped()).spawn()?.wait_with_output()?;
-
+        let output = cmd
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn()?
+            .wait_with_output()?;
+
+        // `cargo`'s own manifest-resolution failures (as opposed to rustc
+        // diagnostics) happen before any compilation starts, so they never
+        // show up in the json message stream and still have to be read off
+        // stderr. The check matches both the prefix and the suffix of the
+        // output: a loose `contains` could misfire on this phrase showing
+        // up in an unrelated dependency's build-script output.
         let err = String::from_utf8_lossy(&output.stderr);
         if err.starts_with("error: failed to parse manifest at")
             && err
                 .trim_end()
                 .ends_with("virtual manifests must be configured with [workspace]")
         {
-            return Err(MissingWorkspace {}.into());
+            return Err(ExpandError::MissingWorkspace.into());
+        }
+
+        let errors: Vec<_> = Message::parse_stream(output.stdout.as_slice())
+            .filter_map(|m| match m {
+                Ok(Message::CompilerMessage(msg)) if msg.message.level == DiagnosticLevel::Error => {
+                    Some(msg.message)
+                }
+                _ => None,
+            })
+            .collect();
+
+        if !errors.is_empty() {
+            return Err(ExpandError::Compile(errors).into());
         }
 
         let content = std::fs::read_to_string(&outfile_path)?;
         if content.is_empty() {
-            anyhow::bail!("ERROR: rustc produced no expanded output");
+            return Err(ExpandError::EmptyOutput.into());
         }
         Ok(content)
     }
@@ -203,37 +445,285 @@ impl Expander {
         filter(content, path)
     }
 
+    /// Returns the `syn::Item`s matched by `selectors` within the given
+    /// dependency, expanding it only once no matter how many selectors are
+    /// passed.
+    pub fn expand_items(
+        &self,
+        package: impl AsRef<str>,
+        selectors: &[Selector],
+    ) -> anyhow::Result<Vec<syn::Item>> {
+        let content = self.expand(package)?;
+        filter_items(content, selectors)
+    }
+
     fn get_metadata(&self) -> anyhow::Result<Metadata> {
         Ok(MetadataCommand::new()
-            .manifest_path(self.manifest_path.as_deref().unwrap_or(&format!(
-                "{}/Cargo.toml",
-                env::var("CARGO_MANIFEST_DIR").expect("No Manifest found")
-            )))
+            .manifest_path(self.resolve_manifest_path()?)
             .features(CargoOpt::AllFeatures)
             .exec()?)
     }
 
+    /// Resolves [`Expander::manifest_path`] (or, absent that, the current
+    /// `CARGO_MANIFEST_DIR`) to an existing `Cargo.toml`.
+    ///
+    /// A relative path is resolved against the current working directory
+    /// rather than handed to `cargo metadata` verbatim, and a path pointing
+    /// at a directory has `Cargo.toml` appended, so callers outside a cargo
+    /// build script (where `CARGO_MANIFEST_DIR` isn't set) can still point
+    /// `with_manifest` at a crate directory or a relative path.
+    fn resolve_manifest_path(&self) -> anyhow::Result<PathBuf> {
+        let raw = match &self.manifest_path {
+            Some(path) => PathBuf::from(path),
+            None => PathBuf::from(format!(
+                "{}/Cargo.toml",
+                env::var("CARGO_MANIFEST_DIR").context("No Manifest found")?
+            )),
+        };
+
+        let cwd = env::current_dir().context("Failed to read current directory")?;
+        let mut path = if raw.is_absolute() { raw } else { cwd.join(raw) };
+
+        if path.is_dir() {
+            path = path.join("Cargo.toml");
+        }
+
+        path.canonicalize()
+            .with_context(|| format!("Manifest path does not exist: `{}`", path.display()))
+    }
+
     fn find_package(&self, name: impl AsRef<str>) -> anyhow::Result<Package> {
-        let name = name.as_ref();
         let metadata = self.get_metadata()?;
-        metadata
-            .packages
-            .into_iter()
-            .find(|pkg| pkg.name == name)
-            .context(format!("No package found with matching name: `{}`", name))
+        find_package_in(&metadata, name.as_ref())
     }
 }
 
+/// Looks up `name` within an already-fetched `Metadata`, without shelling
+/// out to cargo again.
+fn find_package_in(metadata: &Metadata, name: &str) -> anyhow::Result<Package> {
+    metadata
+        .packages
+        .iter()
+        .find(|pkg| pkg.name == name)
+        .cloned()
+        .context(format!("No package found with matching name: `{}`", name))
+}
+
 fn cargo_binary() -> OsString {
     env::var_os("CARGO").unwrap_or_else(|| "cargo".to_owned().into())
 }
 
+/// Resolves the `--target` triple to pass to cargo: an explicit
+/// [`Expander::with_target_triple`] always wins, falling back to
+/// `build.target` from `.cargo/config.toml` only if nothing explicit was set.
+fn resolve_target_triple<'a>(
+    explicit: Option<&'a str>,
+    config: Option<&'a cargo_config::CargoConfig>,
+) -> Option<&'a str> {
+    explicit.or_else(|| config.and_then(|c| c.build.target.as_deref()))
+}
+
 /// Applies the filter on the content
-pub fn filter(mut content: String, filter: Selector) -> anyhow::Result<String> {
-    let mut syntax_tree = syn::parse_file(&content)?;
-    syntax_tree.shebang = None;
-    syntax_tree.attrs.clear();
-    syntax_tree.items = filter.apply_to(&syntax_tree);
-    content = quote!(#syntax_tree).to_string();
-    Ok(content)
+pub fn filter(content: String, filter: Selector) -> anyhow::Result<String> {
+    let items = filter_items(content, &[filter])?;
+    let syntax_tree = syn::File {
+        shebang: None,
+        attrs: Vec::new(),
+        items,
+    };
+    Ok(quote!(#syntax_tree).to_string())
+}
+
+/// Parses `content` and returns the `syn::Item`s matched by `selectors`,
+/// concatenated in order. Applying several selectors this way lets a caller
+/// gather everything it's interested in from a single parse, rather than
+/// re-running the expensive `cargo rustc` expansion once per selector.
+pub fn filter_items(content: String, selectors: &[Selector]) -> anyhow::Result<Vec<syn::Item>> {
+    let syntax_tree = syn::parse_file(&content)?;
+    let mut items = Vec::new();
+    for selector in selectors {
+        items.extend(selector.apply_to(&syntax_tree));
+    }
+    Ok(items)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+
+    // `resolve_manifest_path` falls back to the process-wide current
+    // directory for relative paths, so tests that change it must not run
+    // concurrently with each other.
+    static CWD_LOCK: Mutex<()> = Mutex::new(());
+
+    #[test]
+    fn resolves_directory_to_cargo_toml() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("Cargo.toml"), "").unwrap();
+
+        let expander = Expander::default().with_manifest(tmp.path().to_str().unwrap());
+        let resolved = expander.resolve_manifest_path().unwrap();
+        assert_eq!(
+            resolved,
+            tmp.path().join("Cargo.toml").canonicalize().unwrap()
+        );
+    }
+
+    #[test]
+    fn resolves_relative_path_against_cwd() {
+        let _guard = CWD_LOCK.lock().unwrap();
+
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(tmp.path().join("Cargo.toml"), "").unwrap();
+        let prev_cwd = env::current_dir().unwrap();
+        env::set_current_dir(tmp.path()).unwrap();
+
+        let expander = Expander::default().with_manifest("Cargo.toml");
+        let resolved = expander.resolve_manifest_path();
+
+        env::set_current_dir(prev_cwd).unwrap();
+
+        assert_eq!(
+            resolved.unwrap(),
+            tmp.path().join("Cargo.toml").canonicalize().unwrap()
+        );
+    }
+
+    #[test]
+    fn missing_manifest_path_is_a_descriptive_error() {
+        let tmp = tempfile::tempdir().unwrap();
+        let missing = tmp.path().join("does-not-exist");
+
+        let expander = Expander::default().with_manifest(missing.to_str().unwrap());
+        let err = expander.resolve_manifest_path().unwrap_err();
+        assert!(err.to_string().contains("Manifest path does not exist"));
+    }
+
+    /// Builds a minimal `Package` whose only lib target reports `kinds`, e.g.
+    /// `&["proc-macro"]` or `&["cdylib"]`.
+    fn package_with_lib_kinds(name: &str, kinds: &[&str]) -> Package {
+        let json = serde_json::json!({
+            "name": name,
+            "version": "0.1.0",
+            "id": format!("{name} 0.1.0 (path+file:///tmp/{name})"),
+            "dependencies": [],
+            "targets": [{
+                "name": name,
+                "kind": kinds,
+                "src_path": format!("/tmp/{name}/src/lib.rs"),
+            }],
+            "features": {},
+            "manifest_path": format!("/tmp/{name}/Cargo.toml"),
+        });
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn validate_target_accepts_proc_macro_crates() {
+        let expander = Expander::default();
+        let pkg = package_with_lib_kinds("serde_derive", &["proc-macro"]);
+        assert!(expander.validate_target(&pkg, &Target::Lib).is_ok());
+    }
+
+    #[test]
+    fn validate_target_accepts_cdylib_and_staticlib_only_crates() {
+        let expander = Expander::default();
+        for kind in ["cdylib", "staticlib"] {
+            let pkg = package_with_lib_kinds("ffi-only", &[kind]);
+            assert!(expander.validate_target(&pkg, &Target::Lib).is_ok());
+        }
+    }
+
+    #[test]
+    fn validate_target_rejects_packages_without_a_lib_target() {
+        let expander = Expander::default();
+        let pkg = package_with_lib_kinds("bin-only", &["bin"]);
+        let err = expander.validate_target(&pkg, &Target::Lib).unwrap_err();
+        assert!(err.to_string().contains("No lib target found"));
+    }
+
+    /// Builds a minimal `Metadata` wrapping the given packages, suitable for
+    /// feeding to [`find_package_in`] without a `cargo metadata` shell-out.
+    fn metadata_with_packages(packages: Vec<Package>) -> Metadata {
+        let json = serde_json::json!({
+            "packages": packages,
+            "workspace_members": [],
+            "workspace_root": "/tmp/ws",
+            "target_directory": "/tmp/ws/target",
+            "version": 1,
+        });
+        serde_json::from_value(json).unwrap()
+    }
+
+    #[test]
+    fn find_package_in_locates_package_by_name() {
+        let metadata = metadata_with_packages(vec![
+            package_with_lib_kinds("one", &["lib"]),
+            package_with_lib_kinds("two", &["lib"]),
+        ]);
+        let found = find_package_in(&metadata, "two").unwrap();
+        assert_eq!(found.name, "two");
+    }
+
+    #[test]
+    fn find_package_in_reports_missing_package_by_name() {
+        let metadata = metadata_with_packages(vec![package_with_lib_kinds("one", &["lib"])]);
+        let err = find_package_in(&metadata, "missing").unwrap_err();
+        assert!(err.to_string().contains("missing"));
+    }
+
+    #[test]
+    fn resolve_target_triple_prefers_explicit_over_config() {
+        let config = cargo_config::CargoConfig {
+            build: cargo_config::BuildConfig {
+                rustflags: None,
+                target: Some("x86_64-unknown-linux-gnu".to_string()),
+            },
+        };
+        assert_eq!(
+            resolve_target_triple(Some("wasm32-unknown-unknown"), Some(&config)),
+            Some("wasm32-unknown-unknown")
+        );
+    }
+
+    #[test]
+    fn resolve_target_triple_falls_back_to_config() {
+        let config = cargo_config::CargoConfig {
+            build: cargo_config::BuildConfig {
+                rustflags: None,
+                target: Some("x86_64-unknown-linux-gnu".to_string()),
+            },
+        };
+        assert_eq!(
+            resolve_target_triple(None, Some(&config)),
+            Some("x86_64-unknown-linux-gnu")
+        );
+    }
+
+    #[test]
+    fn resolve_target_triple_is_none_without_explicit_or_config() {
+        assert_eq!(resolve_target_triple(None, None), None);
+    }
+
+    #[test]
+    fn filter_items_concatenates_matches_from_every_selector() {
+        let content = r#"
+            fn one() {}
+            fn two() {}
+            struct Three;
+        "#
+        .to_string();
+
+        let items = filter_items(
+            content,
+            &[
+                Selector::try_from("one").unwrap(),
+                Selector::try_from("two").unwrap(),
+            ],
+        )
+        .unwrap();
+
+        assert_eq!(items.len(), 2);
+    }
 }